@@ -16,12 +16,45 @@ use cpal::{
     SampleFormat, Stream, SupportedStreamConfig,
 };
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+use tauri::Emitter;
+
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 struct NativeRecorder {
     stop_tx: std::sync::mpsc::Sender<()>,
     worker: Option<std::thread::JoinHandle<()>>,
+    /// Relays `recording-level` events off the real-time audio callback
+    /// thread; exits once the stream (and its `LevelEmitter` clones) drop.
+    level_relay: Option<std::thread::JoinHandle<()>>,
+    /// Samples captured since the last drain. `drain_recording_chunk`
+    /// empties it on every call; it has no fixed capacity, so between
+    /// drains it grows with the length of the capture.
     samples: Arc<Mutex<Vec<f32>>>,
     sample_rate: u32,
+    device_name: String,
+    enable_denoise: bool,
+    /// Running count of samples already handed off via
+    /// `drain_recording_chunk`; it is only reported in the drain log line.
+    drained_sample_count: Arc<Mutex<u64>>,
+}
+
+/// One supported input configuration reported by the driver for a device,
+/// e.g. "44.1-48 kHz, 2ch, f32".
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Clone, serde::Serialize)]
+struct AudioInputConfigInfo {
+    sample_rate_min: u32,
+    sample_rate_max: u32,
+    channels: u16,
+    sample_format: String,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Clone, serde::Serialize)]
+struct AudioDeviceInfo {
+    name: String,
+    is_default: bool,
+    configs: Vec<AudioInputConfigInfo>,
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
@@ -34,70 +67,171 @@ struct RecorderState {
 #[derive(Default)]
 struct RecorderState;
 
+/// Minimum spacing between `recording-level` events so a VU meter doesn't
+/// flood the webview with one IPC message per audio callback.
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-fn push_f32_samples(data: &[f32], channels: usize, samples: &Arc<Mutex<Vec<f32>>>) {
-    if channels == 0 || data.is_empty() {
+const LEVEL_EVENT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Relays computed levels off the real-time audio callback thread: the
+/// callback only pushes into `level_tx` (non-blocking), while a dedicated
+/// relay thread owns the actual `AppHandle::emit` IPC call, which can block
+/// on the webview and must never run on the `cpal` capture thread.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+struct LevelEmitter {
+    level_tx: std::sync::mpsc::SyncSender<RecordingLevel>,
+    last_emit: Mutex<std::time::Instant>,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Clone, serde::Serialize)]
+struct RecordingLevel {
+    rms: f32,
+    peak: f32,
+}
+
+/// Spawns the thread that owns `recording-level` event emission, decoupling
+/// it from the real-time audio callback. Exits once every `LevelEmitter`
+/// (and thus every clone of `level_tx`) has been dropped, which happens
+/// when the input stream is torn down at `stop_native_recording`.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn spawn_level_relay(
+    app_handle: tauri::AppHandle,
+) -> (std::sync::mpsc::SyncSender<RecordingLevel>, std::thread::JoinHandle<()>) {
+    let (level_tx, level_rx) = std::sync::mpsc::sync_channel::<RecordingLevel>(8);
+
+    let relay = std::thread::spawn(move || {
+        while let Ok(level) = level_rx.recv() {
+            if let Err(err) = app_handle.emit("recording-level", level) {
+                log::warn!("[NativeRecorder] Failed to emit recording-level event: {err}");
+            }
+        }
+    });
+
+    (level_tx, relay)
+}
+
+/// Computes the throttled RMS/peak amplitude of the mono samples just
+/// captured in this audio callback and hands it off to the level relay
+/// thread. Never blocks: a full relay channel just drops the sample.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn emit_recording_level(emitter: &LevelEmitter, mono_frame: &[f32]) {
+    if mono_frame.is_empty() {
         return;
     }
 
-    let mut captured = match samples.lock() {
+    let mut last_emit = match emitter.last_emit.lock() {
         Ok(guard) => guard,
         Err(err) => {
-            log::error!("[NativeRecorder] Failed to lock sample buffer: {err}");
+            log::error!("[NativeRecorder] Failed to lock level emit timestamp: {err}");
             return;
         }
     };
 
-    for frame in data.chunks(channels) {
-        let frame_sum: f32 = frame.iter().copied().sum();
-        captured.push(frame_sum / frame.len() as f32);
+    let now = std::time::Instant::now();
+    if now.duration_since(*last_emit) < LEVEL_EVENT_INTERVAL {
+        return;
     }
+    *last_emit = now;
+    drop(last_emit);
+
+    let peak = mono_frame.iter().fold(0f32, |acc, sample| acc.max(sample.abs()));
+    let sum_sq: f32 = mono_frame.iter().map(|sample| sample * sample).sum();
+    let rms = (sum_sq / mono_frame.len() as f32).sqrt();
+
+    let _ = emitter.level_tx.try_send(RecordingLevel { rms, peak });
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-fn push_i16_samples(data: &[i16], channels: usize, samples: &Arc<Mutex<Vec<f32>>>) {
+fn push_f32_samples(
+    data: &[f32],
+    channels: usize,
+    samples: &Arc<Mutex<Vec<f32>>>,
+    level_emitter: &Arc<LevelEmitter>,
+) {
     if channels == 0 || data.is_empty() {
         return;
     }
 
-    let mut captured = match samples.lock() {
-        Ok(guard) => guard,
+    let mono_frame: Vec<f32> = data
+        .chunks(channels)
+        .map(|frame| frame.iter().copied().sum::<f32>() / frame.len() as f32)
+        .collect();
+
+    match samples.lock() {
+        Ok(mut captured) => captured.extend_from_slice(&mono_frame),
         Err(err) => {
             log::error!("[NativeRecorder] Failed to lock sample buffer: {err}");
             return;
         }
-    };
-
-    for frame in data.chunks(channels) {
-        let frame_sum: f32 = frame
-            .iter()
-            .map(|sample| (*sample as f32) / (i16::MAX as f32))
-            .sum();
-        captured.push(frame_sum / frame.len() as f32);
     }
+
+    emit_recording_level(level_emitter, &mono_frame);
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
-fn push_u16_samples(data: &[u16], channels: usize, samples: &Arc<Mutex<Vec<f32>>>) {
+fn push_i16_samples(
+    data: &[i16],
+    channels: usize,
+    samples: &Arc<Mutex<Vec<f32>>>,
+    level_emitter: &Arc<LevelEmitter>,
+) {
     if channels == 0 || data.is_empty() {
         return;
     }
 
-    let mut captured = match samples.lock() {
-        Ok(guard) => guard,
+    let mono_frame: Vec<f32> = data
+        .chunks(channels)
+        .map(|frame| {
+            let frame_sum: f32 = frame
+                .iter()
+                .map(|sample| (*sample as f32) / (i16::MAX as f32))
+                .sum();
+            frame_sum / frame.len() as f32
+        })
+        .collect();
+
+    match samples.lock() {
+        Ok(mut captured) => captured.extend_from_slice(&mono_frame),
         Err(err) => {
             log::error!("[NativeRecorder] Failed to lock sample buffer: {err}");
             return;
         }
-    };
+    }
+
+    emit_recording_level(level_emitter, &mono_frame);
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn push_u16_samples(
+    data: &[u16],
+    channels: usize,
+    samples: &Arc<Mutex<Vec<f32>>>,
+    level_emitter: &Arc<LevelEmitter>,
+) {
+    if channels == 0 || data.is_empty() {
+        return;
+    }
 
-    for frame in data.chunks(channels) {
-        let frame_sum: f32 = frame
-            .iter()
-            .map(|sample| ((*sample as f32) / (u16::MAX as f32)) * 2.0 - 1.0)
-            .sum();
-        captured.push(frame_sum / frame.len() as f32);
+    let mono_frame: Vec<f32> = data
+        .chunks(channels)
+        .map(|frame| {
+            let frame_sum: f32 = frame
+                .iter()
+                .map(|sample| ((*sample as f32) / (u16::MAX as f32)) * 2.0 - 1.0)
+                .sum();
+            frame_sum / frame.len() as f32
+        })
+        .collect();
+
+    match samples.lock() {
+        Ok(mut captured) => captured.extend_from_slice(&mono_frame),
+        Err(err) => {
+            log::error!("[NativeRecorder] Failed to lock sample buffer: {err}");
+            return;
+        }
     }
+
+    emit_recording_level(level_emitter, &mono_frame);
 }
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
@@ -105,6 +239,7 @@ fn build_native_input_stream(
     device: &cpal::Device,
     config: &SupportedStreamConfig,
     samples: Arc<Mutex<Vec<f32>>>,
+    level_emitter: Arc<LevelEmitter>,
 ) -> Result<Stream, String> {
     let stream_config = config.config();
     let channels = usize::from(stream_config.channels.max(1));
@@ -112,11 +247,12 @@ fn build_native_input_stream(
     match config.sample_format() {
         SampleFormat::F32 => {
             let callback_samples = Arc::clone(&samples);
+            let callback_emitter = Arc::clone(&level_emitter);
             device
                 .build_input_stream(
                     &stream_config,
                     move |data: &[f32], _| {
-                        push_f32_samples(data, channels, &callback_samples);
+                        push_f32_samples(data, channels, &callback_samples, &callback_emitter);
                     },
                     |err| {
                         log::error!("[NativeRecorder] Input stream error: {err}");
@@ -127,11 +263,12 @@ fn build_native_input_stream(
         }
         SampleFormat::I16 => {
             let callback_samples = Arc::clone(&samples);
+            let callback_emitter = Arc::clone(&level_emitter);
             device
                 .build_input_stream(
                     &stream_config,
                     move |data: &[i16], _| {
-                        push_i16_samples(data, channels, &callback_samples);
+                        push_i16_samples(data, channels, &callback_samples, &callback_emitter);
                     },
                     |err| {
                         log::error!("[NativeRecorder] Input stream error: {err}");
@@ -142,11 +279,12 @@ fn build_native_input_stream(
         }
         SampleFormat::U16 => {
             let callback_samples = Arc::clone(&samples);
+            let callback_emitter = Arc::clone(&level_emitter);
             device
                 .build_input_stream(
                     &stream_config,
                     move |data: &[u16], _| {
-                        push_u16_samples(data, channels, &callback_samples);
+                        push_u16_samples(data, channels, &callback_samples, &callback_emitter);
                     },
                     |err| {
                         log::error!("[NativeRecorder] Input stream error: {err}");
@@ -159,6 +297,226 @@ fn build_native_input_stream(
     }
 }
 
+/// Frame size (samples) used for the spectral-subtraction analysis/synthesis
+/// windows. 50% hop keeps consecutive frames overlapping for the Hann
+/// overlap-add reconstruction.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DENOISE_FRAME_LEN: usize = 1024;
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DENOISE_HOP_LEN: usize = DENOISE_FRAME_LEN / 2;
+/// Number of lowest-energy frames assumed to be silence/background noise
+/// when estimating the noise magnitude profile.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DENOISE_NOISE_FRAMES: usize = 6;
+/// Over-subtraction factor (alpha) applied to the estimated noise magnitude.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DENOISE_SUBTRACTION_FACTOR: f32 = 2.0;
+/// Spectral floor (beta) below which a bin is never subtracted past, to
+/// avoid musical-noise artifacts.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DENOISE_SPECTRAL_FLOOR: f32 = 0.05;
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn hann_window(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (len as f32 - 1.0)).cos()
+        })
+        .collect()
+}
+
+/// Cleans up steady background hum/hiss via FFT spectral subtraction before
+/// the samples are quantized to 16-bit PCM. Estimates a noise magnitude
+/// profile from the quietest frames, then subtracts it (with flooring) from
+/// every frame's spectrum while preserving phase, reconstructing via
+/// windowed overlap-add. Falls back to the original samples if the clip is
+/// too short to analyze or an FFT step fails.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn denoise_samples(samples: &[f32]) -> Vec<f32> {
+    if samples.len() < DENOISE_FRAME_LEN {
+        return samples.to_vec();
+    }
+
+    let window = hann_window(DENOISE_FRAME_LEN);
+    let mut planner = realfft::RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(DENOISE_FRAME_LEN);
+    let ifft = planner.plan_fft_inverse(DENOISE_FRAME_LEN);
+
+    let frame_count = (samples.len() - DENOISE_FRAME_LEN) / DENOISE_HOP_LEN + 1;
+    let mut frame_spectra = Vec::with_capacity(frame_count);
+    let mut frame_energies = Vec::with_capacity(frame_count);
+
+    let mut fft_input = fft.make_input_vec();
+    let mut fft_scratch = fft.make_scratch_vec();
+
+    for frame_idx in 0..frame_count {
+        let start = frame_idx * DENOISE_HOP_LEN;
+        for (i, sample) in fft_input.iter_mut().enumerate() {
+            *sample = samples[start + i] * window[i];
+        }
+
+        // Must be read before `process_with_scratch`, which uses `fft_input`
+        // as scratch space and overwrites it with transform internals.
+        let frame_energy: f32 = fft_input.iter().map(|sample| sample * sample).sum();
+
+        let mut spectrum = fft.make_output_vec();
+        if let Err(err) = fft.process_with_scratch(&mut fft_input, &mut spectrum, &mut fft_scratch) {
+            log::warn!("[Denoise] Forward FFT failed, skipping denoise: {err}");
+            return samples.to_vec();
+        }
+
+        frame_energies.push(frame_energy);
+        frame_spectra.push(spectrum);
+    }
+
+    let mut frames_by_energy: Vec<usize> = (0..frame_count).collect();
+    frames_by_energy.sort_by(|&a, &b| frame_energies[a].total_cmp(&frame_energies[b]));
+
+    let noise_frame_count = DENOISE_NOISE_FRAMES.min(frame_count);
+    let bin_count = frame_spectra[0].len();
+    let mut noise_magnitude = vec![0f32; bin_count];
+    for &idx in &frames_by_energy[..noise_frame_count] {
+        for (bin, complex) in frame_spectra[idx].iter().enumerate() {
+            noise_magnitude[bin] += complex.norm();
+        }
+    }
+    for magnitude in noise_magnitude.iter_mut() {
+        *magnitude /= noise_frame_count as f32;
+    }
+
+    let mut output = vec![0f32; samples.len()];
+    let mut window_sum = vec![0f32; samples.len()];
+    let mut ifft_output = ifft.make_output_vec();
+    let mut ifft_scratch = ifft.make_scratch_vec();
+    let normalization = 1.0 / DENOISE_FRAME_LEN as f32;
+
+    for (frame_idx, spectrum) in frame_spectra.iter_mut().enumerate() {
+        for (bin, complex) in spectrum.iter_mut().enumerate() {
+            let magnitude = complex.norm();
+            let phase = complex.arg();
+            let subtracted = (magnitude - DENOISE_SUBTRACTION_FACTOR * noise_magnitude[bin])
+                .max(DENOISE_SPECTRAL_FLOOR * magnitude);
+            *complex = num_complex::Complex32::from_polar(subtracted, phase);
+        }
+        // DC and Nyquist bins are real; `from_polar` with a phase of pi leaves
+        // a tiny imaginary part that the inverse real FFT rejects.
+        spectrum[0].im = 0.0;
+        if let Some(nyquist) = spectrum.last_mut() {
+            nyquist.im = 0.0;
+        }
+
+        if let Err(err) = ifft.process_with_scratch(spectrum, &mut ifft_output, &mut ifft_scratch) {
+            log::warn!("[Denoise] Inverse FFT failed, skipping denoise: {err}");
+            return samples.to_vec();
+        }
+
+        let start = frame_idx * DENOISE_HOP_LEN;
+        for i in 0..DENOISE_FRAME_LEN {
+            output[start + i] += ifft_output[i] * normalization * window[i];
+            window_sum[start + i] += window[i] * window[i];
+        }
+    }
+
+    // The last frame may not reach the end of the buffer (input length isn't
+    // necessarily a multiple of the hop size); pass that uncovered tail
+    // through untouched instead of leaving it at the initial 0.0 silence.
+    let covered_len = (frame_count - 1) * DENOISE_HOP_LEN + DENOISE_FRAME_LEN;
+    for i in covered_len..samples.len() {
+        output[i] = samples[i];
+        window_sum[i] = 1.0;
+    }
+
+    for (sample, weight) in output.iter_mut().zip(window_sum.iter()) {
+        if *weight > 1e-6 {
+            *sample /= weight;
+        }
+        *sample = sample.clamp(-1.0, 1.0);
+    }
+
+    output
+}
+
+/// Width (ms) of the short-time energy frames used for silence gating.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const VAD_FRAME_MS: u32 = 20;
+/// Multiplier applied to the estimated noise floor to get the voiced/silence
+/// decision threshold.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const VAD_SENSITIVITY_MULTIPLIER: f32 = 2.5;
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn frame_rms(frame: &[f32]) -> f32 {
+    let sum_sq: f32 = frame.iter().map(|sample| sample * sample).sum();
+    (sum_sq / frame.len() as f32).sqrt()
+}
+
+/// Trims leading/trailing silence from `samples` using short-time energy
+/// gating (~20 ms frames against an adaptive noise-floor threshold), and
+/// optionally splits the remainder into separate utterance segments
+/// wherever a silence run is at least `segment_gap_ms` long. Returns one
+/// segment (the whole trimmed clip) when `segment_gap_ms` is `None`.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn trim_and_segment_silence(
+    samples: &[f32],
+    sample_rate: u32,
+    segment_gap_ms: Option<u32>,
+) -> Vec<Vec<f32>> {
+    let frame_len = ((sample_rate as u64 * VAD_FRAME_MS as u64) / 1000).max(1) as usize;
+    if samples.len() < frame_len {
+        return vec![samples.to_vec()];
+    }
+
+    let frame_energy: Vec<f32> = samples.chunks(frame_len).map(frame_rms).collect();
+
+    let noise_floor = {
+        let mut sorted = frame_energy.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let quiet_count = (sorted.len() / 10).max(1);
+        sorted[..quiet_count].iter().sum::<f32>() / quiet_count as f32
+    };
+    let threshold = noise_floor * VAD_SENSITIVITY_MULTIPLIER;
+    let voiced: Vec<bool> = frame_energy.iter().map(|&rms| rms > threshold).collect();
+
+    let (Some(first_voiced), Some(last_voiced)) =
+        (voiced.iter().position(|&v| v), voiced.iter().rposition(|&v| v))
+    else {
+        return vec![Vec::new()];
+    };
+
+    let Some(gap_ms) = segment_gap_ms else {
+        let start = first_voiced * frame_len;
+        let end = ((last_voiced + 1) * frame_len).min(samples.len());
+        return vec![samples[start..end].to_vec()];
+    };
+
+    let gap_frames = gap_ms.div_ceil(VAD_FRAME_MS).max(1) as usize;
+    let mut segments = Vec::new();
+    let mut segment_start_frame = first_voiced;
+    let mut last_voiced_in_segment = first_voiced;
+    let mut silence_run = 0usize;
+
+    for frame_idx in first_voiced..=last_voiced {
+        if voiced[frame_idx] {
+            if silence_run >= gap_frames && frame_idx > segment_start_frame {
+                let start = segment_start_frame * frame_len;
+                let end = ((last_voiced_in_segment + 1) * frame_len).min(samples.len());
+                segments.push(samples[start..end].to_vec());
+                segment_start_frame = frame_idx;
+            }
+            last_voiced_in_segment = frame_idx;
+            silence_run = 0;
+        } else {
+            silence_run += 1;
+        }
+    }
+
+    let start = segment_start_frame * frame_len;
+    let end = ((last_voiced_in_segment + 1) * frame_len).min(samples.len());
+    segments.push(samples[start..end].to_vec());
+
+    segments
+}
+
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 fn encode_wav_bytes(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>, String> {
     let wav_spec = hound::WavSpec {
@@ -187,9 +545,180 @@ fn encode_wav_bytes(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>, String
     Ok(output.into_inner())
 }
 
+/// How often the device watcher re-enumerates input devices to detect
+/// hotplug events.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+const DEVICE_WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Clone, serde::Serialize)]
+struct InputDeviceEvent {
+    name: String,
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn enumerate_input_device_names() -> Result<std::collections::HashSet<String>, String> {
+    let host = cpal::default_host();
+    let devices = host
+        .input_devices()
+        .map_err(|err| format!("Failed to enumerate input devices: {err}"))?;
+
+    Ok(devices.filter_map(|device| device.name().ok()).collect())
+}
+
+/// Periodically re-enumerates `cpal` input devices on a background thread,
+/// emitting `input-device-added`/`input-device-removed` events whenever the
+/// set changes, and `recording-device-lost` if the device backing an
+/// in-progress `NativeRecorder` disappears.
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn spawn_device_watcher(app_handle: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut known_devices = enumerate_input_device_names().unwrap_or_default();
+
+        loop {
+            std::thread::sleep(DEVICE_WATCH_INTERVAL);
+
+            let current_devices = match enumerate_input_device_names() {
+                Ok(devices) => devices,
+                Err(err) => {
+                    log::warn!("[DeviceWatcher] Failed to enumerate input devices: {err}");
+                    continue;
+                }
+            };
+
+            for added in current_devices.difference(&known_devices) {
+                if let Err(err) = app_handle.emit(
+                    "input-device-added",
+                    InputDeviceEvent { name: added.clone() },
+                ) {
+                    log::warn!("[DeviceWatcher] Failed to emit input-device-added: {err}");
+                }
+            }
+
+            for removed in known_devices.difference(&current_devices) {
+                if let Err(err) = app_handle.emit(
+                    "input-device-removed",
+                    InputDeviceEvent { name: removed.clone() },
+                ) {
+                    log::warn!("[DeviceWatcher] Failed to emit input-device-removed: {err}");
+                }
+
+                let recording_device_lost = app_handle
+                    .try_state::<RecorderState>()
+                    .and_then(|state| {
+                        state
+                            .recorder
+                            .lock()
+                            .ok()
+                            .map(|guard| guard.as_ref().map(|r| r.device_name.clone()))
+                    })
+                    .flatten()
+                    .is_some_and(|active_device| active_device == *removed);
+
+                if recording_device_lost {
+                    if let Err(err) = app_handle.emit(
+                        "recording-device-lost",
+                        InputDeviceEvent { name: removed.clone() },
+                    ) {
+                        log::warn!("[DeviceWatcher] Failed to emit recording-device-lost: {err}");
+                    }
+                }
+            }
+
+            known_devices = current_devices;
+        }
+    });
+}
+
+/// Resolves an input device by name, falling back to the host default when
+/// `device_name` is `None` or does not match any currently enumerated device.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn resolve_input_device(
+    host: &cpal::Host,
+    device_name: Option<&str>,
+) -> Result<cpal::Device, String> {
+    if let Some(wanted) = device_name {
+        let found = host
+            .input_devices()
+            .map_err(|err| format!("Failed to enumerate input devices: {err}"))?
+            .find(|device| matches!(device.name(), Ok(name) if name == wanted));
+
+        if let Some(device) = found {
+            return Ok(device);
+        }
+
+        log::warn!(
+            "[NativeRecorder] Requested input device '{wanted}' not found, falling back to default."
+        );
+    }
+
+    host.default_input_device()
+        .ok_or_else(|| "No audio input device found.".to_string())
+}
+
+/// Walks the host's input devices and reports each one's name and supported
+/// configs (sample rate range, channel count, sample format) so the frontend
+/// can present a device picker.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn list_input_devices() -> Result<Vec<AudioDeviceInfo>, String> {
+    let host = cpal::default_host();
+    let default_name = host.default_input_device().and_then(|d| d.name().ok());
+
+    let devices = host
+        .input_devices()
+        .map_err(|err| format!("Failed to enumerate input devices: {err}"))?;
+
+    let mut infos = Vec::new();
+    for device in devices {
+        let name = match device.name() {
+            Ok(name) => name,
+            Err(err) => {
+                log::warn!("[NativeRecorder] Skipping unnamed input device: {err}");
+                continue;
+            }
+        };
+
+        let configs = match device.supported_input_configs() {
+            Ok(ranges) => ranges
+                .map(|range| AudioInputConfigInfo {
+                    sample_rate_min: range.min_sample_rate().0,
+                    sample_rate_max: range.max_sample_rate().0,
+                    channels: range.channels(),
+                    sample_format: format!("{:?}", range.sample_format()),
+                })
+                .collect(),
+            Err(err) => {
+                log::warn!("[NativeRecorder] Skipping input device '{name}' with unreadable configs: {err}");
+                continue;
+            }
+        };
+
+        let is_default = default_name.as_deref() == Some(name.as_str());
+        infos.push(AudioDeviceInfo {
+            name,
+            is_default,
+            configs,
+        });
+    }
+
+    Ok(infos)
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
 #[tauri::command]
-fn start_native_recording(state: tauri::State<'_, RecorderState>) -> Result<(), String> {
+fn list_input_devices() -> Result<Vec<()>, String> {
+    Err("Input device enumeration is not available on this platform.".to_string())
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn start_native_recording(
+    state: tauri::State<'_, RecorderState>,
+    app_handle: tauri::AppHandle,
+    device_name: Option<String>,
+    enable_denoise: Option<bool>,
+) -> Result<(), String> {
     let mut recorder = state
         .inner()
         .recorder
@@ -202,33 +731,42 @@ fn start_native_recording(state: tauri::State<'_, RecorderState>) -> Result<(),
 
     let samples = Arc::new(Mutex::new(Vec::<f32>::new()));
     let (stop_tx, stop_rx) = std::sync::mpsc::channel::<()>();
-    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<u32, String>>();
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<Result<(u32, String), String>>();
+
+    let (level_tx, level_relay) = spawn_level_relay(app_handle);
+    let level_emitter = Arc::new(LevelEmitter {
+        level_tx,
+        last_emit: Mutex::new(std::time::Instant::now()),
+    });
 
     let worker_samples = Arc::clone(&samples);
+    let wanted_device_name = device_name.clone();
     let worker = std::thread::spawn(move || {
-        let setup_result = (|| -> Result<(Stream, u32), String> {
+        let setup_result = (|| -> Result<(Stream, u32, String), String> {
             let host = cpal::default_host();
-            let device = host
-                .default_input_device()
-                .ok_or_else(|| "No audio input device found.".to_string())?;
+            let device = resolve_input_device(&host, wanted_device_name.as_deref())?;
+            let resolved_name = device
+                .name()
+                .unwrap_or_else(|_| "Unknown device".to_string());
 
             let supported_config = device
                 .default_input_config()
                 .map_err(|err| format!("Failed to read default input config: {err}"))?;
 
             let sample_rate = supported_config.sample_rate().0;
-            let stream = build_native_input_stream(&device, &supported_config, worker_samples)?;
+            let stream =
+                build_native_input_stream(&device, &supported_config, worker_samples, level_emitter)?;
 
             stream
                 .play()
                 .map_err(|err| format!("Failed to start input stream: {err}"))?;
 
-            Ok((stream, sample_rate))
+            Ok((stream, sample_rate, resolved_name))
         })();
 
         match setup_result {
-            Ok((stream, sample_rate)) => {
-                let _ = ready_tx.send(Ok(sample_rate));
+            Ok((stream, sample_rate, resolved_name)) => {
+                let _ = ready_tx.send(Ok((sample_rate, resolved_name)));
 
                 if stop_rx.recv().is_err() {
                     log::warn!("[NativeRecorder] Stop signal channel closed unexpectedly.");
@@ -242,20 +780,25 @@ fn start_native_recording(state: tauri::State<'_, RecorderState>) -> Result<(),
         }
     });
 
-    let sample_rate = ready_rx
+    let (sample_rate, resolved_device_name) = ready_rx
         .recv()
         .map_err(|_| "Native recorder thread failed to initialize.".to_string())??;
 
     log::info!(
-        "[NativeRecorder] Recording started with sample rate {} Hz",
+        "[NativeRecorder] Recording started on '{}' with sample rate {} Hz",
+        resolved_device_name,
         sample_rate
     );
 
     *recorder = Some(NativeRecorder {
         stop_tx,
         worker: Some(worker),
+        level_relay: Some(level_relay),
         samples,
         sample_rate,
+        device_name: resolved_device_name,
+        enable_denoise: enable_denoise.unwrap_or(false),
+        drained_sample_count: Arc::new(Mutex::new(0)),
     });
 
     Ok(())
@@ -263,7 +806,12 @@ fn start_native_recording(state: tauri::State<'_, RecorderState>) -> Result<(),
 
 #[cfg(not(any(target_os = "android", target_os = "ios")))]
 #[tauri::command]
-fn stop_native_recording(state: tauri::State<'_, RecorderState>) -> Result<Vec<u8>, String> {
+fn stop_native_recording(
+    state: tauri::State<'_, RecorderState>,
+    app_handle: tauri::AppHandle,
+    trim_silence: Option<bool>,
+    segment_gap_ms: Option<u32>,
+) -> Result<Vec<Vec<u8>>, String> {
     let mut recorder = state
         .inner()
         .recorder
@@ -287,6 +835,15 @@ fn stop_native_recording(state: tauri::State<'_, RecorderState>) -> Result<Vec<u
             .map_err(|_| "Native recorder thread panicked.".to_string())?;
     }
 
+    // The stream (and its LevelEmitter/level_tx clones) is dropped inside
+    // the worker thread above, so the relay's channel is already closed and
+    // this join returns promptly.
+    if let Some(level_relay) = native_recorder.level_relay.take() {
+        if level_relay.join().is_err() {
+            log::warn!("[NativeRecorder] Level relay thread panicked.");
+        }
+    }
+
     let captured_samples = native_recorder
         .samples
         .lock()
@@ -294,6 +851,26 @@ fn stop_native_recording(state: tauri::State<'_, RecorderState>) -> Result<Vec<u
         .clone();
 
     if captured_samples.is_empty() {
+        let device_still_present = enumerate_input_device_names()
+            .map(|devices| devices.contains(&native_recorder.device_name))
+            .unwrap_or(true);
+
+        if !device_still_present {
+            if let Err(err) = app_handle.emit(
+                "recording-device-lost",
+                InputDeviceEvent {
+                    name: native_recorder.device_name.clone(),
+                },
+            ) {
+                log::warn!("[NativeRecorder] Failed to emit recording-device-lost: {err}");
+            }
+
+            return Err(format!(
+                "Recording device '{}' was disconnected during capture.",
+                native_recorder.device_name
+            ));
+        }
+
         return Err("No audio was captured. Please try again.".to_string());
     }
 
@@ -302,21 +879,233 @@ fn stop_native_recording(state: tauri::State<'_, RecorderState>) -> Result<Vec<u
         captured_samples.len()
     );
 
-    encode_wav_bytes(&captured_samples, native_recorder.sample_rate)
+    let samples_to_encode = if native_recorder.enable_denoise {
+        denoise_samples(&captured_samples)
+    } else {
+        captured_samples
+    };
+
+    let segments = if trim_silence.unwrap_or(false) {
+        trim_and_segment_silence(&samples_to_encode, native_recorder.sample_rate, segment_gap_ms)
+    } else {
+        vec![samples_to_encode]
+    };
+
+    let non_empty_segments: Vec<Vec<f32>> = segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if non_empty_segments.is_empty() {
+        return Err("No audio was captured. Please try again.".to_string());
+    }
+
+    non_empty_segments
+        .into_iter()
+        .map(|segment| encode_wav_bytes(&segment, native_recorder.sample_rate))
+        .collect()
+}
+
+/// Returns finalized WAV bytes for the samples captured since the last
+/// drain (or since recording started, on the first call), without
+/// interrupting the in-progress capture. Draining empties the sample buffer,
+/// so the frontend can pipeline audio to the transcription backend
+/// near-real-time instead of waiting for `stop_native_recording`.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn drain_recording_chunk(state: tauri::State<'_, RecorderState>) -> Result<Vec<u8>, String> {
+    let recorder = state
+        .inner()
+        .recorder
+        .lock()
+        .map_err(|_| "Failed to lock native recorder state.".to_string())?;
+
+    let native_recorder = recorder
+        .as_ref()
+        .ok_or_else(|| "No native recording session is running.".to_string())?;
+
+    let chunk_samples: Vec<f32> = native_recorder
+        .samples
+        .lock()
+        .map_err(|_| "Failed to lock native sample buffer.".to_string())?
+        .drain(..)
+        .collect();
+
+    let mut drained_sample_count = native_recorder
+        .drained_sample_count
+        .lock()
+        .map_err(|_| "Failed to lock drained sample counter.".to_string())?;
+    *drained_sample_count += chunk_samples.len() as u64;
+
+    log::info!(
+        "[NativeRecorder] Drained {} samples ({} total since recording started)",
+        chunk_samples.len(),
+        drained_sample_count
+    );
+
+    encode_wav_bytes(&chunk_samples, native_recorder.sample_rate)
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[tauri::command]
+fn drain_recording_chunk(_state: tauri::State<'_, RecorderState>) -> Result<Vec<u8>, String> {
+    Err("Native recording is not available on this platform.".to_string())
 }
 
 #[cfg(any(target_os = "android", target_os = "ios"))]
 #[tauri::command]
-fn start_native_recording(_state: tauri::State<'_, RecorderState>) -> Result<(), String> {
+fn start_native_recording(
+    _state: tauri::State<'_, RecorderState>,
+    _app_handle: tauri::AppHandle,
+    _device_name: Option<String>,
+    _enable_denoise: Option<bool>,
+) -> Result<(), String> {
     Err("Native recording is not available on this platform.".to_string())
 }
 
 #[cfg(any(target_os = "android", target_os = "ios"))]
 #[tauri::command]
-fn stop_native_recording(_state: tauri::State<'_, RecorderState>) -> Result<Vec<u8>, String> {
+fn stop_native_recording(
+    _state: tauri::State<'_, RecorderState>,
+    _app_handle: tauri::AppHandle,
+    _trim_silence: Option<bool>,
+    _segment_gap_ms: Option<u32>,
+) -> Result<Vec<Vec<u8>>, String> {
     Err("Native recording is not available on this platform.".to_string())
 }
 
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Default)]
+struct TtsState {
+    tts: Mutex<Option<tts::Tts>>,
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[derive(Default)]
+struct TtsState;
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[derive(Clone, serde::Serialize)]
+struct TtsVoiceInfo {
+    id: String,
+    name: String,
+    language: String,
+}
+
+/// Lazily initializes the shared `tts::Tts` handle on first use and runs
+/// `f` against it, mirroring the lazy-setup pattern `RecorderState` uses
+/// for the native recorder.
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+fn with_tts<T>(
+    state: &TtsState,
+    f: impl FnOnce(&mut tts::Tts) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut guard = state
+        .tts
+        .lock()
+        .map_err(|_| "Failed to lock text-to-speech engine state.".to_string())?;
+
+    if guard.is_none() {
+        let engine = tts::Tts::default()
+            .map_err(|err| format!("Failed to initialize text-to-speech engine: {err}"))?;
+        *guard = Some(engine);
+    }
+
+    f(guard.as_mut().expect("TTS engine initialized above"))
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn list_tts_voices(state: tauri::State<'_, TtsState>) -> Result<Vec<TtsVoiceInfo>, String> {
+    with_tts(state.inner(), |tts| {
+        let voices = tts
+            .voices()
+            .map_err(|err| format!("Failed to list text-to-speech voices: {err}"))?;
+
+        Ok(voices
+            .into_iter()
+            .map(|voice| TtsVoiceInfo {
+                id: voice.id(),
+                name: voice.name(),
+                language: voice.language().to_string(),
+            })
+            .collect())
+    })
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn speak_text(
+    state: tauri::State<'_, TtsState>,
+    text: String,
+    voice_id: Option<String>,
+    rate: Option<f32>,
+    pitch: Option<f32>,
+) -> Result<(), String> {
+    with_tts(state.inner(), |tts| {
+        if let Some(voice_id) = &voice_id {
+            let voices = tts
+                .voices()
+                .map_err(|err| format!("Failed to list text-to-speech voices: {err}"))?;
+
+            match voices.into_iter().find(|voice| &voice.id() == voice_id) {
+                Some(voice) => tts
+                    .set_voice(&voice)
+                    .map_err(|err| format!("Failed to set text-to-speech voice: {err}"))?,
+                None => log::warn!("[TTS] Requested voice '{voice_id}' not found, using current voice."),
+            }
+        }
+
+        if let Some(rate) = rate {
+            tts.set_rate(rate)
+                .map_err(|err| format!("Failed to set text-to-speech rate: {err}"))?;
+        }
+
+        if let Some(pitch) = pitch {
+            tts.set_pitch(pitch)
+                .map_err(|err| format!("Failed to set text-to-speech pitch: {err}"))?;
+        }
+
+        tts.speak(&text, true)
+            .map_err(|err| format!("Failed to speak text: {err}"))?;
+
+        Ok(())
+    })
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios")))]
+#[tauri::command]
+fn stop_speaking(state: tauri::State<'_, TtsState>) -> Result<(), String> {
+    with_tts(state.inner(), |tts| {
+        tts.stop()
+            .map_err(|err| format!("Failed to stop text-to-speech playback: {err}"))
+    })
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[tauri::command]
+fn list_tts_voices(_state: tauri::State<'_, TtsState>) -> Result<Vec<()>, String> {
+    Err("Text-to-speech is not available on this platform.".to_string())
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[tauri::command]
+fn speak_text(
+    _state: tauri::State<'_, TtsState>,
+    _text: String,
+    _voice_id: Option<String>,
+    _rate: Option<f32>,
+    _pitch: Option<f32>,
+) -> Result<(), String> {
+    Err("Text-to-speech is not available on this platform.".to_string())
+}
+
+#[cfg(any(target_os = "android", target_os = "ios"))]
+#[tauri::command]
+fn stop_speaking(_state: tauri::State<'_, TtsState>) -> Result<(), String> {
+    Err("Text-to-speech is not available on this platform.".to_string())
+}
+
 #[cfg(desktop)]
 fn toggle_main_window(app_handle: &tauri::AppHandle) {
     let Some(window) = app_handle.get_webview_window("main") else {
@@ -406,9 +1195,15 @@ async fn deepl_request(
 pub fn run() {
     tauri::Builder::default()
         .manage(RecorderState::default())
+        .manage(TtsState::default())
         .invoke_handler(tauri::generate_handler![
+            list_input_devices,
             start_native_recording,
             stop_native_recording,
+            drain_recording_chunk,
+            list_tts_voices,
+            speak_text,
+            stop_speaking,
             deepl_request
         ])
         .setup(|app| {
@@ -472,6 +1267,9 @@ pub fn run() {
                     })?;
             }
 
+            #[cfg(not(any(target_os = "android", target_os = "ios")))]
+            spawn_device_watcher(app.handle().clone());
+
             app.handle().plugin(
                 tauri_plugin_log::Builder::default()
                     .level(log_level)